@@ -0,0 +1,56 @@
+use std::fmt;
+
+/// Signal that the current query has been cancelled and should unwind
+/// rather than keep computing a result nobody wants anymore.
+///
+/// A `Cancelled` value is never returned from a query directly; instead it
+/// is thrown as a panic payload (see [`Cancelled::throw`]) so that it
+/// unwinds through however many stack frames of tracked-function calls sit
+/// between the cancellation point and the caller that knows how to handle
+/// it, typically the owner of a [`crate::storage::Snapshot`] who catches it
+/// with [`Cancelled::catch`] and retries once the write that triggered it
+/// has landed.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Cancelled {
+    /// The query was unwound because another thread was waiting to start a
+    /// new revision (i.e. apply a write) and this thread's snapshot was in
+    /// its way.
+    PendingWrite,
+}
+
+impl Cancelled {
+    /// Panics with this value as the payload. Intended to be called from
+    /// points where a long-running read has observed that it should give up,
+    /// such as [`crate::storage::Storage::unwind_if_cancelled`].
+    pub(crate) fn throw(self) -> ! {
+        std::panic::panic_any(self)
+    }
+
+    /// Runs `f`, catching a `Cancelled` panic thrown from within it (via
+    /// [`Cancelled::throw`]) and returning it as an `Err` instead of letting
+    /// it propagate further. Any other panic is resumed unchanged.
+    pub fn catch<F, T>(f: F) -> Result<T, Cancelled>
+    where
+        F: FnOnce() -> T + std::panic::UnwindSafe,
+    {
+        match std::panic::catch_unwind(f) {
+            Ok(t) => Ok(t),
+            Err(payload) => match payload.downcast::<Cancelled>() {
+                Ok(cancelled) => Err(*cancelled),
+                Err(payload) => std::panic::resume_unwind(payload),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let why = match self {
+            Cancelled::PendingWrite => "pending write",
+        };
+        write!(f, "cancelled because of {why}")
+    }
+}
+
+impl std::error::Error for Cancelled {}
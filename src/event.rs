@@ -0,0 +1,54 @@
+use crate::storage::IngredientIndex;
+
+/// An event emitted by the salsa runtime as it validates memoized values,
+/// executes queries, and coordinates between threads.
+///
+/// Events are delivered through [`crate::Database::salsa_event`], which
+/// defaults to doing nothing. The callback takes a closure rather than an
+/// `Event` directly so that, in the common case where no handler is
+/// installed, constructing the event itself (which can require formatting
+/// a database key) is skipped entirely.
+#[derive(Debug)]
+pub enum Event {
+    /// Emitted just before we execute a tracked function's query, either
+    /// because it has never run before or because its memoized value could
+    /// not be validated without re-running it.
+    ///
+    /// Not yet wired up to an emission point: the actual function-execution
+    /// boundary lives in `fetch`/`maybe_changed_after`, which aren't part of
+    /// this chunk. It is *not* emitted from the generic by-index ingredient
+    /// dispatch (`DatabaseGen::lookup_ingredient`), since that dispatch is
+    /// also used for things unrelated to executing a query, such as cycle
+    /// recovery checks and `IngredientCache` population.
+    WillExecute {
+        /// The ingredient whose query is about to run.
+        database_key: IngredientIndex,
+    },
+
+    /// Emitted when we find an existing memo for a query, before we've
+    /// checked whether its dependencies are still up to date. This fires
+    /// whether or not the memo turns out to be reusable; it does *not* mean
+    /// the value was confirmed valid — confirming validity requires walking
+    /// dependencies, which happens later (in `fetch`/`maybe_changed_after`).
+    DidLookupMemo {
+        /// The ingredient whose memo was found.
+        database_key: IngredientIndex,
+    },
+
+    /// Emitted when a reader thread checks whether the current revision has
+    /// been cancelled (see [`crate::storage::DatabaseGen::unwind_if_cancelled`]),
+    /// at a query boundary.
+    WillCheckCancellation,
+
+    /// Describes this thread being about to block waiting on another thread
+    /// that is already computing the same query.
+    ///
+    /// The runtime's blocking path does not emit this variant; no code
+    /// constructs it.
+    WillBlockOn {
+        /// The thread already computing `database_key`.
+        other_thread: std::thread::ThreadId,
+        /// The ingredient whose query we are waiting on.
+        database_key: IngredientIndex,
+    },
+}
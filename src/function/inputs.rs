@@ -1,4 +1,4 @@
-use crate::{runtime::local_state::QueryOrigin, Id};
+use crate::{runtime::local_state::QueryOrigin, Database, Event, Id};
 
 use super::{Configuration, IngredientImpl};
 
@@ -6,7 +6,15 @@ impl<C> IngredientImpl<C>
 where
     C: Configuration,
 {
-    pub(super) fn origin(&self, key: Id) -> Option<QueryOrigin> {
-        self.memo_map.get(key).map(|m| m.revisions.origin.clone())
+    pub(super) fn origin(&self, db: &dyn Database, key: Id) -> Option<QueryOrigin> {
+        db.unwind_if_cancelled();
+
+        let memo = self.memo_map.get(key);
+        if memo.is_some() {
+            db.salsa_event(|| Event::DidLookupMemo {
+                database_key: self.ingredient_index(),
+            });
+        }
+        memo.map(|m| m.revisions.origin.clone())
     }
 }
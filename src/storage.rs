@@ -1,4 +1,5 @@
 use std::any::{Any, TypeId};
+use std::sync::Arc;
 
 use orx_concurrent_vec::ConcurrentVec;
 use parking_lot::Mutex;
@@ -81,6 +82,12 @@ pub unsafe trait DatabaseGen: Any {
 
     /// Gets the salsa runtime
     fn runtime_mut(&mut self) -> &mut Runtime;
+
+    /// Unwinds with [`crate::Cancelled`] if another handle onto this storage
+    /// is waiting to start a new revision. Query implementations should call
+    /// this at their boundaries so a pending write can preempt them instead
+    /// of waiting for them to finish on their own.
+    fn unwind_if_cancelled(&self);
 }
 
 /// This is the *actual* trait that the macro generates.
@@ -138,6 +145,11 @@ unsafe impl<T: HasStorage> DatabaseGen for T {
         &mut self.storage_mut().runtime
     }
 
+    fn unwind_if_cancelled(&self) {
+        self.salsa_event(|| crate::Event::WillCheckCancellation);
+        self.storage().unwind_if_cancelled()
+    }
+
     fn lookup_ingredient_mut(
         &mut self,
         index: IngredientIndex,
@@ -209,7 +221,12 @@ pub struct Storage<Db: Database> {
     /// Data shared across all databases. This contains the ingredients needed by each jar.
     /// See the ["jars and ingredients" chapter](https://salsa-rs.github.io/salsa/plumbing/jars_and_ingredients.html)
     /// for more detailed description.
-    shared: Shared<Db>,
+    ///
+    /// Wrapped in an `Arc` so that [`Storage::snapshot`] can hand a second
+    /// database handle a reference to the very same ingredients without
+    /// copying them: memos written through one handle become visible to
+    /// the other the moment they're written.
+    shared: Arc<Shared<Db>>,
 
     /// The runtime for this particular salsa database handle.
     /// Each handle gets its own runtime, but the runtimes have shared state between them.
@@ -239,25 +256,49 @@ struct Shared<Db: Database> {
 
     /// Indices of ingredients that require reset when a new revision starts.
     ingredients_requiring_reset: ConcurrentVec<IngredientIndex>,
+
+    /// Number of `Storage` handles onto this `Shared` that are currently
+    /// live, including the original and every outstanding snapshot.
+    /// [`Storage::lookup_ingredient_mut`] waits for this to drop back to `1`
+    /// (itself) before starting a new revision, so that no snapshot reader
+    /// observes a write in progress.
+    snapshot_count: std::sync::atomic::AtomicUsize,
+
+    /// Set while a handle is waiting to start a new revision. Reader threads
+    /// consult this via [`Storage::unwind_if_cancelled`] at query
+    /// boundaries and unwind with [`crate::Cancelled::PendingWrite`] if they
+    /// see it set, so that the writer doesn't have to wait for a read that
+    /// may run arbitrarily long.
+    pending_write: std::sync::atomic::AtomicBool,
 }
 
 // ANCHOR: default
 impl<Db: Database> Default for Storage<Db> {
     fn default() -> Self {
         Self {
-            shared: Shared {
+            shared: Arc::new(Shared {
                 upcasts: Default::default(),
                 nonce: NONCE.nonce(),
                 jar_map: Default::default(),
                 ingredients_vec: Default::default(),
                 ingredients_requiring_reset: Default::default(),
-            },
+                snapshot_count: std::sync::atomic::AtomicUsize::new(1),
+                pending_write: std::sync::atomic::AtomicBool::new(false),
+            }),
             runtime: Runtime::default(),
         }
     }
 }
 // ANCHOR_END: default
 
+impl<Db: Database> Drop for Storage<Db> {
+    fn drop(&mut self) {
+        self.shared
+            .snapshot_count
+            .fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
 impl<Db: Database> Storage<Db> {
     /// Add an upcast function to type `T`.
     pub fn add_upcast<T: ?Sized + Any>(
@@ -316,6 +357,17 @@ impl<Db: Database> Storage<Db> {
         &mut self,
         index: IngredientIndex,
     ) -> (&mut dyn Ingredient, &mut Runtime) {
+        use std::sync::atomic::Ordering;
+
+        // Signal any live snapshots that a write is pending, so their next
+        // call to `unwind_if_cancelled` unwinds instead of racing us, then
+        // wait for them to actually drop before mutating shared state.
+        self.shared.pending_write.store(true, Ordering::SeqCst);
+        while self.shared.snapshot_count.load(Ordering::SeqCst) != 1 {
+            std::thread::yield_now();
+        }
+        self.shared.pending_write.store(false, Ordering::SeqCst);
+
         self.runtime.new_revision();
 
         for index in self.shared.ingredients_requiring_reset.iter() {
@@ -339,15 +391,168 @@ impl<Db: Database> Storage<Db> {
     pub fn runtime(&self) -> &Runtime {
         &self.runtime
     }
+
+    /// Creates a second handle onto the same storage, suitable for giving to
+    /// another thread.
+    ///
+    /// The returned `Storage` shares `jar_map`, `ingredients_vec` and all
+    /// other jar data with `self` (they point at the same `Arc<Shared<Db>>`),
+    /// so any memoized value computed through one handle is immediately
+    /// visible through the other. It gets its own [`Runtime`], however, so it
+    /// can independently track what revision it has last synchronized with.
+    ///
+    /// This is the low-level primitive backing [`ParallelDatabase::snapshot`];
+    /// most users should go through that trait rather than call this
+    /// directly.
+    pub fn snapshot(&self) -> Self {
+        self.shared
+            .snapshot_count
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        Self {
+            shared: self.shared.clone(),
+            runtime: self.runtime.snapshot(),
+        }
+    }
+
+    /// Unwinds with [`crate::Cancelled::PendingWrite`] if another handle is
+    /// currently waiting to start a new revision.
+    ///
+    /// Reader threads should call this periodically at query boundaries (the
+    /// same places that would emit [`crate::Event::WillCheckCancellation`])
+    /// so that a pending write doesn't have to wait for every in-flight read
+    /// to finish on its own.
+    pub fn unwind_if_cancelled(&self) {
+        if self
+            .shared
+            .pending_write
+            .load(std::sync::atomic::Ordering::SeqCst)
+        {
+            crate::Cancelled::PendingWrite.throw();
+        }
+    }
+}
+
+/// A database that can be used from multiple threads in parallel.
+///
+/// `#[salsa::db]` databases implement this trait as long as they are
+/// `Clone` and `Send`. Since [`Storage`] itself has no `Clone` impl (it would defeat
+/// the point: a plain clone wouldn't share jar data), the `storage` field
+/// can only be populated through a hand-written `Clone` impl, and that impl
+/// **must** clone it by calling [`Storage::snapshot`], not by any other
+/// means (e.g. `Storage::default`), or the "clone" will silently be a
+/// totally independent, empty database rather than a snapshot of `self`.
+/// `snapshot`'s default implementation relies on this and does not call
+/// `Storage::snapshot` itself, so it costs exactly one Arc clone and one
+/// fresh `Runtime`, not two.
+///
+/// # Safety
+///
+/// The returned [`Snapshot`] must not be used while `self` is in the middle
+/// of a write (i.e. between [`DatabaseGen::lookup_ingredient_mut`] being
+/// called and the resulting `&mut Runtime` being dropped). The cancellation
+/// machinery described in [`Storage::unwind_if_cancelled`] exists precisely to
+/// uphold this: a pending write blocks until all live snapshots are gone.
+pub unsafe trait ParallelDatabase: HasStorage + Clone + Send {
+    /// Creates a second handle to this database that can be sent to another
+    /// thread and read from concurrently with `self`.
+    ///
+    /// Relies on `Self::clone` to produce the new handle's `storage` field
+    /// via [`Storage::snapshot`] (see the trait-level docs); it is not
+    /// called again here.
+    fn snapshot(&self) -> Snapshot<Self> {
+        unsafe { Snapshot::new(self.clone()) }
+    }
 }
 
-/// Caches a pointer to an ingredient in a database.
-/// Optimized for the case of a single database.
+unsafe impl<Db> ParallelDatabase for Db where Db: HasStorage + Clone + Send {}
+
+/// A guard around a database snapshot produced by [`ParallelDatabase::snapshot`].
+///
+/// `Snapshot` derefs to `&Db`, so it can be used just like the database it
+/// wraps, but it is deliberately `Send` and not `Sync`: each snapshot owns
+/// its own [`Runtime`], and sharing a `&Snapshot` across threads would let
+/// two threads drive that runtime's revision bookkeeping at once.
+pub struct Snapshot<Db: ParallelDatabase> {
+    db: Db,
+
+    // `Cell` is `!Sync`; including one here makes `Snapshot` `!Sync` too
+    // without depending on the unstable `negative_impls` feature.
+    _not_sync: std::marker::PhantomData<std::cell::Cell<()>>,
+}
+
+impl<Db: ParallelDatabase> Snapshot<Db> {
+    /// Creates a new snapshot wrapping `db`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `db` was produced by
+    /// [`ParallelDatabase::snapshot`] (or an equivalent fresh [`Storage`]
+    /// handle onto shared jar data) and not, say, an unrelated database that
+    /// merely happens to share a type.
+    pub unsafe fn new(db: Db) -> Self {
+        Snapshot {
+            db,
+            _not_sync: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<Db: ParallelDatabase> std::ops::Deref for Snapshot<Db> {
+    type Target = Db;
+
+    fn deref(&self) -> &Db {
+        &self.db
+    }
+}
+
+/// Number of distinct databases whose ingredient pointer [`IngredientCache`]
+/// remembers at once. Sized for the common case of a handful of live
+/// databases (e.g. several `RootDatabase`s kept alive across an LSP server's
+/// tests); beyond this a cache miss evicts one of the remembered databases.
+const CACHE_SLOTS: usize = 4;
+
+/// One slot of an [`IngredientCache`].
+struct CacheSlot<I> {
+    /// Points at a leaked `(Nonce<StorageNonce>, *const I)` pair, or null if
+    /// this slot has never been filled yet. We leak old entries on eviction
+    /// rather than reclaiming them: evictions only happen once more than
+    /// `CACHE_SLOTS` distinct databases have used the cache, the entries are
+    /// tiny, and leaking avoids needing an epoch-based reclamation scheme to
+    /// let a concurrent reader safely keep dereferencing an entry we just
+    /// replaced.
+    entry: std::sync::atomic::AtomicPtr<(Nonce<StorageNonce>, *const I)>,
+}
+
+impl<I> CacheSlot<I> {
+    const fn empty() -> Self {
+        Self {
+            entry: std::sync::atomic::AtomicPtr::new(std::ptr::null_mut()),
+        }
+    }
+}
+
+/// Caches pointers to an ingredient for up to [`CACHE_SLOTS`] databases,
+/// keyed by each database's [`StorageNonce`].
+///
+/// A program with a single, long-lived database only ever touches slot `0`,
+/// so after its first lookup (which allocates the slot's entry) that path is
+/// allocation-free and branch-predictable. A program juggling several
+/// databases at once keeps the fast path for each of them: each gets its own
+/// slot. Entries are never removed when their database is dropped, so once
+/// more than `CACHE_SLOTS` distinct databases have used the cache, each miss
+/// evicts a slot round-robin, and the evicted database goes through
+/// `lookup_ingredient` + `assert_type` again on its next access.
 pub struct IngredientCache<I>
 where
     I: Ingredient,
 {
-    cached_data: std::sync::OnceLock<(Nonce<StorageNonce>, *const I)>,
+    slots: [CacheSlot<I>; CACHE_SLOTS],
+
+    /// Slot to evict next once all slots are full. Plain round-robin rather
+    /// than true LRU: simpler, and eviction only matters once more than
+    /// `CACHE_SLOTS` distinct databases have used the cache, at which point
+    /// we're already past the fast path this cache optimizes for.
+    next_victim: std::sync::atomic::AtomicUsize,
 }
 
 unsafe impl<I> Sync for IngredientCache<I> where I: Ingredient + Sync {}
@@ -368,7 +573,13 @@ where
     /// Create a new cache
     pub const fn new() -> Self {
         Self {
-            cached_data: std::sync::OnceLock::new(),
+            slots: [
+                CacheSlot::empty(),
+                CacheSlot::empty(),
+                CacheSlot::empty(),
+                CacheSlot::empty(),
+            ],
+            next_victim: std::sync::atomic::AtomicUsize::new(0),
         }
     }
 
@@ -379,16 +590,37 @@ where
         storage: &'s dyn Database,
         create_index: impl Fn() -> IngredientIndex,
     ) -> &'s I {
-        let &(nonce, ingredient) = self.cached_data.get_or_init(|| {
-            let ingredient = self.create_ingredient(storage, &create_index);
-            (storage.nonce(), ingredient as *const I)
-        });
-
-        if storage.nonce() == nonce {
-            unsafe { &*ingredient }
-        } else {
-            self.create_ingredient(storage, &create_index)
+        use std::sync::atomic::Ordering;
+
+        let nonce = storage.nonce();
+
+        for slot in &self.slots {
+            let entry = slot.entry.load(Ordering::Acquire);
+            if !entry.is_null() {
+                // SAFETY: `entry` was published by a `store` below of a
+                // pointer obtained from `Box::into_raw`, and is never freed.
+                let &(slot_nonce, ingredient) = unsafe { &*entry };
+                if slot_nonce == nonce {
+                    return unsafe { &*ingredient };
+                }
+            }
         }
+
+        let ingredient = self.create_ingredient(storage, &create_index);
+        let entry = Box::into_raw(Box::new((nonce, ingredient as *const I)));
+
+        let victim = self
+            .slots
+            .iter()
+            .position(|slot| slot.entry.load(Ordering::Acquire).is_null())
+            .unwrap_or_else(|| {
+                self.next_victim.fetch_add(1, Ordering::Relaxed) % CACHE_SLOTS
+            });
+        // If another thread races us for the same slot, one entry is simply
+        // leaked in favor of the other; both are valid, so this is benign.
+        self.slots[victim].entry.store(entry, Ordering::Release);
+
+        ingredient
     }
 
     fn create_ingredient<'s>(
@@ -0,0 +1,86 @@
+use std::panic::AssertUnwindSafe;
+use std::time::Duration;
+
+use salsa::{Cancelled, Database as SalsaDatabase, ParallelDatabase};
+
+mod common;
+use common::{HasLogger, Logger};
+
+#[salsa::db]
+trait Db: salsa::Database + HasLogger {}
+
+#[salsa::input]
+struct MyInput {
+    field: u32,
+}
+
+/// Spins reading `input` until it observes a cancellation, calling
+/// `unwind_if_cancelled` at each iteration the way a real query boundary
+/// would.
+#[salsa::tracked]
+fn read_until_cancelled<'db>(db: &'db dyn Db, input: MyInput) -> u32 {
+    db.push_log(format!("read_until_cancelled({input:?})"));
+    loop {
+        db.unwind_if_cancelled();
+        std::thread::yield_now();
+        let _ = input.field(db);
+    }
+}
+
+#[salsa::db]
+#[derive(Default)]
+struct Database {
+    storage: salsa::Storage<Self>,
+    logger: Logger,
+}
+
+// `Storage` deliberately isn't `Clone` (a plain clone wouldn't share jar
+// data), so `ParallelDatabase::snapshot`'s default impl requires a
+// hand-written `Clone` that populates `storage` via `Storage::snapshot`.
+impl Clone for Database {
+    fn clone(&self) -> Self {
+        Database {
+            storage: self.storage.snapshot(),
+            logger: self.logger.clone(),
+        }
+    }
+}
+
+#[salsa::db]
+impl salsa::Database for Database {}
+
+#[salsa::db]
+impl Db for Database {}
+
+impl HasLogger for Database {
+    fn logger(&self) -> &Logger {
+        &self.logger
+    }
+}
+
+/// A snapshot spinning in a long-running read should unwind with
+/// `Cancelled::PendingWrite` once the owning thread starts a write, and the
+/// write itself should only proceed once that unwind has dropped the
+/// snapshot (see `Storage::lookup_ingredient_mut`'s spin-wait on
+/// `snapshot_count`).
+#[test]
+fn write_cancels_live_snapshot_read() {
+    let mut db = Database::default();
+    let input = MyInput::new(&db, 1);
+
+    let snapshot = db.snapshot();
+    let worker = std::thread::spawn(move || {
+        Cancelled::catch(AssertUnwindSafe(|| read_until_cancelled(&*snapshot, input)))
+    });
+
+    // Give the worker a chance to start spinning inside `read_until_cancelled`
+    // before we start a write. `lookup_ingredient_mut` blocks until the
+    // worker's snapshot is dropped, which only happens once it unwinds.
+    std::thread::sleep(Duration::from_millis(50));
+    db.synthetic_write(salsa::Durability::LOW);
+
+    assert!(matches!(
+        worker.join().unwrap(),
+        Err(Cancelled::PendingWrite)
+    ));
+}